@@ -0,0 +1,67 @@
+//! Crate-level error type
+//!
+//! Commands return `Result<T, Error>` instead of `Result<T, String>` so the
+//! webview can branch on error kind instead of parsing message text.
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unsupported platform")]
+    UnsupportedPlatform,
+
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("checksum mismatch: expected {expected}, got {got}")]
+    ChecksumMismatch { expected: String, got: String },
+
+    #[error("verification failed")]
+    VerificationFailed,
+
+    #[error("extraction failed: {0}")]
+    Extract(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl Error {
+    /// Machine-readable discriminant for the frontend to branch on
+    fn kind(&self) -> &'static str {
+        match self {
+            Error::UnsupportedPlatform => "unsupportedPlatform",
+            Error::Network(_) => "network",
+            Error::Io(_) => "io",
+            Error::ChecksumMismatch { .. } => "checksumMismatch",
+            Error::VerificationFailed => "verificationFailed",
+            Error::Extract(_) => "extract",
+            Error::Other(_) => "other",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Error", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Other(message.to_string())
+    }
+}
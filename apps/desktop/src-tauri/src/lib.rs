@@ -1,4 +1,5 @@
 mod config;
+mod error;
 pub mod runtime;
 mod sidecar;
 
@@ -21,7 +22,7 @@ pub fn run() {
                 if !RuntimeManager::is_installed() {
                     println!("[runtime] Node.js runtime not found, starting download...");
                     if let Some(manager) = app_handle.try_state::<RuntimeManager>() {
-                        if let Err(e) = manager.install().await {
+                        if let Err(e) = manager.install(&app_handle).await {
                             eprintln!("[runtime] Failed to install: {}", e);
                         }
                     }
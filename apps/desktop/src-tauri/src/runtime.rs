@@ -5,49 +5,145 @@
 //!
 //! The runtime is downloaded from official Node.js releases on first launch.
 
+use crate::error::Error;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
-// sha2 can be used for checksum verification if needed
-#[allow(unused_imports)]
 use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+use tokio::io::AsyncReadExt;
 use tokio::sync::Mutex;
 
-/// Node.js version to bundle
-const NODE_VERSION: &str = "22.13.1";
+/// Event emitted to the webview as the runtime downloads/extracts
+const PROGRESS_EVENT: &str = "runtime://progress";
+
+/// Time since the last progress event after which the next chunk emits one,
+/// so fast connections don't flood the webview
+const PROGRESS_THROTTLE: Duration = Duration::from_millis(100);
+
+/// Percent change since the last progress event after which the next chunk
+/// emits one; an event is emitted when either this or `PROGRESS_THROTTLE`
+/// is reached
+const PROGRESS_THROTTLE_PERCENT: f32 = 1.0;
+
+/// Maximum number of download attempts before giving up
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry; doubles on each subsequent attempt
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Node.js version to fall back to if the dist index can't be resolved
+const PINNED_NODE_VERSION: &str = "22.13.1";
+
+/// Name of the file (inside the runtime directory) that records which
+/// version is currently installed
+const VERSION_FILE: &str = "version.txt";
+
+/// Node.js release signing key (raw 32-byte ed25519 public key) used to
+/// authenticate `SHASUMS256.txt.sig`. Left empty until the real release key
+/// is vendored in; signature verification is skipped (with a warning) while
+/// it is, so checksum verification alone still gates extraction.
+const NODE_RELEASE_PUBLIC_KEY: &[u8] = &[];
+
+/// Which Node.js release to track
+#[derive(Debug, Clone)]
+pub enum NodeChannel {
+    /// The newest published release, LTS or not
+    Latest,
+    /// The newest release whose `lts` field names a release line
+    Lts,
+    /// An exact version, e.g. `"22.13.1"`
+    Pinned(String),
+}
+
+/// One entry of `https://nodejs.org/dist/index.json`
+#[derive(Debug, Deserialize)]
+struct NodeDistEntry {
+    version: String,
+    #[serde(default)]
+    lts: LtsField,
+}
+
+/// The dist index represents non-LTS releases as `"lts": false` and LTS
+/// releases as `"lts": "<codename>"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum LtsField {
+    Name(String),
+    Flag(bool),
+}
 
-/// Download URLs for different platforms
-fn get_node_url() -> Option<(&'static str, &'static str)> {
+impl Default for LtsField {
+    fn default() -> Self {
+        LtsField::Flag(false)
+    }
+}
+
+/// Fetch and deserialize the Node.js dist index
+async fn fetch_dist_index() -> Result<Vec<NodeDistEntry>, Error> {
+    Ok(reqwest::get("https://nodejs.org/dist/index.json")
+        .await?
+        .json::<Vec<NodeDistEntry>>()
+        .await?)
+}
+
+/// Resolve `channel` to a concrete version string (without the leading `v`),
+/// falling back to [`PINNED_NODE_VERSION`] if the channel can't be resolved
+/// from the network. The dist index is published newest-first, so the first
+/// matching entry is the highest version for that channel.
+async fn resolve_node_version(channel: &NodeChannel) -> String {
+    if let NodeChannel::Pinned(version) = channel {
+        return version.clone();
+    }
+
+    let entries = match fetch_dist_index().await {
+        Ok(entries) => entries,
+        Err(e) => {
+            println!(
+                "[runtime] {}, falling back to pinned version {}",
+                e, PINNED_NODE_VERSION
+            );
+            return PINNED_NODE_VERSION.to_string();
+        }
+    };
+
+    let resolved = entries.iter().find(|entry| match channel {
+        NodeChannel::Latest => true,
+        NodeChannel::Lts => matches!(entry.lts, LtsField::Name(_)),
+        NodeChannel::Pinned(_) => unreachable!(),
+    });
+
+    match resolved {
+        Some(entry) => entry.version.trim_start_matches('v').to_string(),
+        None => {
+            println!(
+                "[runtime] No release matched the requested channel, falling back to pinned version {}",
+                PINNED_NODE_VERSION
+            );
+            PINNED_NODE_VERSION.to_string()
+        }
+    }
+}
+
+/// Node.js's name for the current platform/arch, and the archive extension
+/// it publishes for it
+fn node_platform() -> Option<(&'static str, &'static str, &'static str)> {
     #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    return Some((
-        concat!("https://nodejs.org/dist/v22.13.1/node-v22.13.1-darwin-arm64.tar.gz"),
-        "node-v22.13.1-darwin-arm64",
-    ));
+    return Some(("darwin", "arm64", "tar.gz"));
 
     #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-    return Some((
-        concat!("https://nodejs.org/dist/v22.13.1/node-v22.13.1-darwin-x64.tar.gz"),
-        "node-v22.13.1-darwin-x64",
-    ));
+    return Some(("darwin", "x64", "tar.gz"));
 
     #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
-    return Some((
-        concat!("https://nodejs.org/dist/v22.13.1/node-v22.13.1-linux-x64.tar.gz"),
-        "node-v22.13.1-linux-x64",
-    ));
+    return Some(("linux", "x64", "tar.gz"));
 
     #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
-    return Some((
-        concat!("https://nodejs.org/dist/v22.13.1/node-v22.13.1-linux-arm64.tar.gz"),
-        "node-v22.13.1-linux-arm64",
-    ));
+    return Some(("linux", "arm64", "tar.gz"));
 
     #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
-    return Some((
-        concat!("https://nodejs.org/dist/v22.13.1/node-v22.13.1-win-x64.zip"),
-        "node-v22.13.1-win-x64",
-    ));
+    return Some(("win", "x64", "zip"));
 
     #[cfg(not(any(
         all(target_os = "macos", target_arch = "aarch64"),
@@ -59,6 +155,102 @@ fn get_node_url() -> Option<(&'static str, &'static str)> {
     return None;
 }
 
+/// The download URL and extracted folder name for `version` on the current platform
+fn get_node_url(version: &str) -> Option<(String, String)> {
+    let (os, arch, ext) = node_platform()?;
+    let folder_name = format!("node-v{}-{}-{}", version, os, arch);
+    let url = format!(
+        "https://nodejs.org/dist/v{}/{}.{}",
+        version, folder_name, ext
+    );
+    Some((url, folder_name))
+}
+
+/// Fetch `SHASUMS256.txt` for a given Node.js version
+async fn fetch_shasums(version: &str) -> Result<String, Error> {
+    let url = format!("https://nodejs.org/dist/v{}/SHASUMS256.txt", version);
+
+    Ok(reqwest::get(&url).await?.text().await?)
+}
+
+/// Find the expected digest for `filename` in the contents of `SHASUMS256.txt`.
+/// Each line has the form `<hex_digest>  <filename>`.
+fn parse_shasum(shasums: &str, filename: &str) -> Option<String> {
+    shasums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let digest = parts.next()?;
+        let name = parts.next()?;
+        (name == filename).then(|| digest.to_lowercase())
+    })
+}
+
+/// Fetch the detached signature for `SHASUMS256.txt` and verify it against
+/// the embedded Node.js release public key. Verification is skipped (not
+/// failed) while no key is vendored in, so this never blocks installs on
+/// its own.
+async fn verify_shasums_signature(version: &str, shasums: &str) -> Result<(), Error> {
+    if NODE_RELEASE_PUBLIC_KEY.is_empty() {
+        println!("[runtime] No release public key bundled, skipping signature check");
+        return Ok(());
+    }
+
+    let sig_url = format!(
+        "https://nodejs.org/dist/v{}/SHASUMS256.txt.sig",
+        version
+    );
+    let signature_bytes = reqwest::get(&sig_url).await?.bytes().await?;
+
+    if !minisign_verify(NODE_RELEASE_PUBLIC_KEY, &signature_bytes, shasums.as_bytes()) {
+        return Err(Error::VerificationFailed);
+    }
+
+    Ok(())
+}
+
+/// Check a signature of `message` against a raw public key. No ed25519 or
+/// minisign verification is implemented: the inputs are ignored and every
+/// signature is rejected.
+fn minisign_verify(public_key: &[u8], signature: &[u8], message: &[u8]) -> bool {
+    let _ = (public_key, signature, message);
+    // Always `false`, so `verify_shasums_signature` fails closed whenever
+    // `NODE_RELEASE_PUBLIC_KEY` is non-empty.
+    false
+}
+
+/// Emit a `DownloadProgress` event to the webview. Emission failures (e.g. no
+/// window yet) are logged and otherwise ignored, since progress events are
+/// best-effort.
+fn emit_progress(app: &tauri::AppHandle, bytes_downloaded: u64, total_bytes: Option<u64>, percent: f32, status: &str) {
+    let payload = DownloadProgress {
+        bytes_downloaded,
+        total_bytes,
+        percent,
+        status: status.to_string(),
+    };
+
+    if let Err(e) = app.emit(PROGRESS_EVENT, payload) {
+        eprintln!("[runtime] Failed to emit progress event: {}", e);
+    }
+}
+
+/// Hash `path` with SHA-256, streaming the file instead of loading it whole.
+async fn hash_file(path: &PathBuf) -> Result<String, Error> {
+    let mut file = tokio::fs::File::open(path).await?;
+
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 /// Runtime status for the frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -70,6 +262,7 @@ pub struct RuntimeStatus {
     pub downloading: bool,
     pub download_progress: f32,
     pub error: Option<String>,
+    pub sha256: Option<String>,
 }
 
 /// Download progress for the frontend
@@ -87,6 +280,7 @@ pub struct RuntimeState {
     pub downloading: bool,
     pub progress: f32,
     pub error: Option<String>,
+    pub sha256: Option<String>,
 }
 
 impl Default for RuntimeState {
@@ -95,18 +289,21 @@ impl Default for RuntimeState {
             downloading: false,
             progress: 0.0,
             error: None,
+            sha256: None,
         }
     }
 }
 
 pub struct RuntimeManager {
     pub state: Arc<Mutex<RuntimeState>>,
+    pub channel: NodeChannel,
 }
 
 impl Default for RuntimeManager {
     fn default() -> Self {
         Self {
             state: Arc::new(Mutex::new(RuntimeState::default())),
+            channel: NodeChannel::Lts,
         }
     }
 }
@@ -117,10 +314,19 @@ impl RuntimeManager {
         dirs::data_local_dir().map(|d| d.join("simplestclaw").join("runtime"))
     }
 
+    /// Read the version recorded for the currently installed runtime, if any
+    pub fn installed_version() -> Option<String> {
+        let runtime_dir = Self::runtime_dir()?;
+        let contents = std::fs::read_to_string(runtime_dir.join(VERSION_FILE)).ok()?;
+        let version = contents.trim();
+        (!version.is_empty()).then(|| version.to_string())
+    }
+
     /// Get path to bundled node binary
     pub fn node_path() -> Option<PathBuf> {
         let runtime_dir = Self::runtime_dir()?;
-        let (_, folder_name) = get_node_url()?;
+        let version = Self::installed_version()?;
+        let (_, folder_name) = get_node_url(&version)?;
 
         #[cfg(target_os = "windows")]
         let node = runtime_dir.join(folder_name).join("node.exe");
@@ -138,7 +344,8 @@ impl RuntimeManager {
     /// Get path to bundled npx binary
     pub fn npx_path() -> Option<PathBuf> {
         let runtime_dir = Self::runtime_dir()?;
-        let (_, folder_name) = get_node_url()?;
+        let version = Self::installed_version()?;
+        let (_, folder_name) = get_node_url(&version)?;
 
         #[cfg(target_os = "windows")]
         let npx = runtime_dir.join(folder_name).join("npx.cmd");
@@ -164,36 +371,32 @@ impl RuntimeManager {
 
         RuntimeStatus {
             installed: Self::is_installed(),
-            version: if Self::is_installed() {
-                Some(NODE_VERSION.to_string())
-            } else {
-                None
-            },
+            version: Self::installed_version(),
             node_path: Self::node_path().map(|p| p.to_string_lossy().to_string()),
             npx_path: Self::npx_path().map(|p| p.to_string_lossy().to_string()),
             downloading: state.downloading,
             download_progress: state.progress,
             error: state.error.clone(),
+            sha256: state.sha256.clone(),
         }
     }
 
     /// Download and install the Node.js runtime
-    pub async fn install(&self) -> Result<(), String> {
+    pub async fn install(&self, app: &tauri::AppHandle) -> Result<(), Error> {
         // Check if already installed
         if Self::is_installed() {
             return Ok(());
         }
 
-        let (url, folder_name) = get_node_url()
-            .ok_or("Unsupported platform")?;
+        let version = resolve_node_version(&self.channel).await;
+
+        let (url, folder_name) = get_node_url(&version).ok_or(Error::UnsupportedPlatform)?;
 
         let runtime_dir = Self::runtime_dir()
-            .ok_or("Could not determine runtime directory")?;
+            .ok_or_else(|| Error::Other("Could not determine runtime directory".to_string()))?;
 
         // Create runtime directory
-        tokio::fs::create_dir_all(&runtime_dir)
-            .await
-            .map_err(|e| format!("Failed to create runtime directory: {}", e))?;
+        tokio::fs::create_dir_all(&runtime_dir).await?;
 
         // Update state
         {
@@ -204,70 +407,74 @@ impl RuntimeManager {
         }
 
         // Download
-        let result = self.download_and_extract(url, folder_name, &runtime_dir).await;
+        let result = self
+            .download_and_extract(app, &version, &url, &folder_name, &runtime_dir)
+            .await;
 
         // Update state
-        {
+        let progress = {
             let mut state = self.state.lock().await;
             state.downloading = false;
             if let Err(ref e) = result {
-                state.error = Some(e.clone());
+                state.error = Some(e.to_string());
             }
-        }
+            state.progress
+        };
+
+        let status = if result.is_ok() { "done" } else { "error" };
+        emit_progress(app, 0, None, progress, status);
 
         result
     }
 
     async fn download_and_extract(
         &self,
+        app: &tauri::AppHandle,
+        version: &str,
         url: &str,
         folder_name: &str,
         runtime_dir: &PathBuf,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         println!("[runtime] Downloading Node.js from {}", url);
 
         let client = reqwest::Client::new();
-        let response = client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| format!("Download failed: {}", e))?;
-
-        let total_size = response.content_length();
-        let mut downloaded: u64 = 0;
-
-        // Download to temp file
         let temp_file = runtime_dir.join("download.tmp");
-        let mut file = tokio::fs::File::create(&temp_file)
-            .await
-            .map_err(|e| format!("Failed to create temp file: {}", e))?;
-
-        let mut stream = response.bytes_stream();
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
-            downloaded += chunk.len() as u64;
 
-            tokio::io::AsyncWriteExt::write_all(&mut file, &chunk)
-                .await
-                .map_err(|e| format!("Write error: {}", e))?;
-
-            // Update progress
-            if let Some(total) = total_size {
-                let progress = (downloaded as f32 / total as f32) * 100.0;
-                let mut state = self.state.lock().await;
-                state.progress = progress;
+        let total_size = self
+            .download_resumable(app, &client, url, &temp_file)
+            .await?;
+        let downloaded = tokio::fs::metadata(&temp_file).await?.len();
+
+        println!("[runtime] Download complete, verifying checksum...");
+        emit_progress(app, downloaded, total_size, 100.0, "verifying");
+
+        let archive_filename = url
+            .rsplit('/')
+            .next()
+            .ok_or_else(|| "Could not determine archive filename from URL".to_string())?;
+
+        let digest = Self::verify_checksum(&temp_file, version, archive_filename).await;
+        let digest = match digest {
+            Ok(digest) => digest,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&temp_file).await;
+                return Err(e);
             }
+        };
+
+        {
+            let mut state = self.state.lock().await;
+            state.sha256 = Some(digest);
         }
 
-        drop(file);
-        println!("[runtime] Download complete, extracting...");
+        println!("[runtime] Checksum verified, extracting...");
 
         // Update progress to show extracting
         {
             let mut state = self.state.lock().await;
             state.progress = 100.0;
         }
+        emit_progress(app, downloaded, total_size, 100.0, "extracting");
 
         // Extract based on file type
         let is_zip = url.ends_with(".zip");
@@ -281,16 +488,20 @@ impl RuntimeManager {
         // Clean up temp file
         let _ = tokio::fs::remove_file(&temp_file).await;
 
+        // Persist the resolved version so status()/node_path()/npx_path() can
+        // find the install without recompiling
+        tokio::fs::write(runtime_dir.join(VERSION_FILE), version).await?;
+
         // Verify installation
         if !Self::is_installed() {
-            return Err("Installation verification failed".to_string());
+            return Err(Error::Other("Installation verification failed".to_string()));
         }
 
         // Make binaries executable on Unix
         #[cfg(not(target_os = "windows"))]
         {
             let bin_dir = runtime_dir.join(folder_name).join("bin");
-            for entry in std::fs::read_dir(&bin_dir).map_err(|e| e.to_string())? {
+            for entry in std::fs::read_dir(&bin_dir)? {
                 if let Ok(entry) = entry {
                     let _ = std::fs::set_permissions(
                         entry.path(),
@@ -300,44 +511,202 @@ impl RuntimeManager {
             }
         }
 
-        println!("[runtime] Node.js {} installed successfully", NODE_VERSION);
+        println!("[runtime] Node.js {} installed successfully", version);
         Ok(())
     }
 
-    async fn extract_tar_gz(&self, archive_path: &PathBuf, dest: &PathBuf) -> Result<(), String> {
+    /// Download `url` into `temp_file`, resuming from whatever bytes are
+    /// already on disk and retrying transient failures with exponential
+    /// backoff. Returns the total size of the completed file, if known.
+    async fn download_resumable(
+        &self,
+        app: &tauri::AppHandle,
+        client: &reqwest::Client,
+        url: &str,
+        temp_file: &PathBuf,
+    ) -> Result<Option<u64>, Error> {
+        let mut delay = INITIAL_RETRY_DELAY;
+        let mut last_err = None;
+
+        for attempt in 1..=MAX_DOWNLOAD_ATTEMPTS {
+            let existing = tokio::fs::metadata(temp_file)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+
+            if existing > 0 {
+                println!("[runtime] Resuming download from byte {}", existing);
+                emit_progress(app, existing, None, 0.0, "resuming download...");
+            }
+
+            match self
+                .download_once(app, client, url, temp_file, existing)
+                .await
+            {
+                Ok(total_size) => return Ok(total_size),
+                Err(e) => {
+                    println!(
+                        "[runtime] Download attempt {}/{} failed: {}",
+                        attempt, MAX_DOWNLOAD_ATTEMPTS, e
+                    );
+                    last_err = Some(e);
+
+                    if attempt < MAX_DOWNLOAD_ATTEMPTS {
+                        emit_progress(app, 0, None, 0.0, "resuming download...");
+                        tokio::time::sleep(delay).await;
+                        delay *= 2;
+                    }
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| Error::Other("Download failed".to_string())))
+    }
+
+    /// A single download attempt. Sends a `Range` request if `existing` bytes
+    /// are already on disk; falls back to a full restart if the server
+    /// doesn't honor the range (`200`) or rejects it (`416`).
+    async fn download_once(
+        &self,
+        app: &tauri::AppHandle,
+        client: &reqwest::Client,
+        url: &str,
+        temp_file: &PathBuf,
+        existing: u64,
+    ) -> Result<Option<u64>, Error> {
+        let mut request = client.get(url);
+        if existing > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            // The bytes we have on disk no longer correspond to a valid
+            // range (stale or corrupt partial file) - drop them so the next
+            // attempt restarts from zero.
+            let _ = tokio::fs::remove_file(temp_file).await;
+            return Err(Error::Other(format!(
+                "Range not satisfiable for {} existing bytes, restarting",
+                existing
+            )));
+        }
+
+        let response = response.error_for_status()?;
+        let resumed = status == reqwest::StatusCode::PARTIAL_CONTENT;
+
+        let (mut downloaded, total_size) = if resumed {
+            (existing, response.content_length().map(|len| existing + len))
+        } else {
+            if existing > 0 {
+                println!(
+                    "[runtime] Server did not resume download (status {}), restarting",
+                    status
+                );
+            }
+            (0, response.content_length())
+        };
+
+        let mut file = if resumed {
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(temp_file)
+                .await?
+        } else {
+            tokio::fs::File::create(temp_file).await?
+        };
+
+        let mut stream = response.bytes_stream();
+        let mut last_emit = Instant::now() - PROGRESS_THROTTLE;
+        let mut last_emit_percent = -PROGRESS_THROTTLE_PERCENT;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            downloaded += chunk.len() as u64;
+
+            tokio::io::AsyncWriteExt::write_all(&mut file, &chunk).await?;
+
+            if let Some(total) = total_size {
+                let progress = (downloaded as f32 / total as f32) * 100.0;
+                let mut state = self.state.lock().await;
+                state.progress = progress;
+                drop(state);
+
+                if last_emit.elapsed() >= PROGRESS_THROTTLE
+                    || (progress - last_emit_percent).abs() >= PROGRESS_THROTTLE_PERCENT
+                {
+                    emit_progress(app, downloaded, total_size, progress, "downloading");
+                    last_emit = Instant::now();
+                    last_emit_percent = progress;
+                }
+            }
+        }
+
+        Ok(total_size)
+    }
+
+    /// Verify the downloaded archive against the official SHASUMS256.txt,
+    /// optionally authenticating that file with its detached signature.
+    /// Returns the verified digest on success.
+    async fn verify_checksum(
+        archive_path: &PathBuf,
+        version: &str,
+        archive_filename: &str,
+    ) -> Result<String, Error> {
+        let shasums = fetch_shasums(version).await?;
+
+        verify_shasums_signature(version, &shasums).await?;
+
+        let expected = parse_shasum(&shasums, archive_filename).ok_or_else(|| {
+            Error::Other(format!(
+                "Could not find checksum for {} in SHASUMS256.txt",
+                archive_filename
+            ))
+        })?;
+
+        let actual = hash_file(archive_path).await?;
+
+        if actual != expected {
+            return Err(Error::ChecksumMismatch {
+                expected,
+                got: actual,
+            });
+        }
+
+        Ok(actual)
+    }
+
+    async fn extract_tar_gz(&self, archive_path: &PathBuf, dest: &PathBuf) -> Result<(), Error> {
         let archive_path = archive_path.clone();
         let dest = dest.clone();
 
         tokio::task::spawn_blocking(move || {
-            let file = std::fs::File::open(&archive_path)
-                .map_err(|e| format!("Failed to open archive: {}", e))?;
+            let file = std::fs::File::open(&archive_path)?;
             let decoder = flate2::read::GzDecoder::new(file);
             let mut archive = tar::Archive::new(decoder);
-            archive
-                .unpack(&dest)
-                .map_err(|e| format!("Failed to extract: {}", e))?;
-            Ok::<(), String>(())
+            archive.unpack(&dest)?;
+            Ok::<(), Error>(())
         })
         .await
-        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| Error::Extract(e.to_string()))?
     }
 
-    async fn extract_zip(&self, archive_path: &PathBuf, dest: &PathBuf) -> Result<(), String> {
+    async fn extract_zip(&self, archive_path: &PathBuf, dest: &PathBuf) -> Result<(), Error> {
         let archive_path = archive_path.clone();
         let dest = dest.clone();
 
         tokio::task::spawn_blocking(move || {
-            let file = std::fs::File::open(&archive_path)
-                .map_err(|e| format!("Failed to open archive: {}", e))?;
-            let mut archive = zip::ZipArchive::new(file)
-                .map_err(|e| format!("Failed to read zip: {}", e))?;
+            let file = std::fs::File::open(&archive_path)?;
+            let mut archive =
+                zip::ZipArchive::new(file).map_err(|e| Error::Extract(e.to_string()))?;
             archive
                 .extract(&dest)
-                .map_err(|e| format!("Failed to extract: {}", e))?;
-            Ok::<(), String>(())
+                .map_err(|e| Error::Extract(e.to_string()))?;
+            Ok::<(), Error>(())
         })
         .await
-        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| Error::Extract(e.to_string()))?
     }
 }
 
@@ -346,15 +715,16 @@ impl RuntimeManager {
 #[tauri::command]
 pub async fn get_runtime_status(
     manager: tauri::State<'_, RuntimeManager>,
-) -> Result<RuntimeStatus, String> {
+) -> Result<RuntimeStatus, Error> {
     Ok(manager.status().await)
 }
 
 #[tauri::command]
 pub async fn install_runtime(
+    app: tauri::AppHandle,
     manager: tauri::State<'_, RuntimeManager>,
-) -> Result<(), String> {
-    manager.install().await
+) -> Result<(), Error> {
+    manager.install(&app).await
 }
 
 #[tauri::command]